@@ -2,13 +2,16 @@ use std::path::PathBuf;
 
 use interpulse::api::minecraft::Version;
 use onelauncher::constants::{NATIVE_ARCH, TARGET_OS, VERSION};
-use onelauncher::data::{Loader, ManagedPackage, MinecraftCredentials, PackageData, Settings};
-use onelauncher::package::content;
+use onelauncher::data::{
+	JavaVersion, Loader, ManagedPackage, MinecraftCredentials, PackageData, Settings,
+};
+use onelauncher::package::{content, mrpack};
 use onelauncher::store::{Cluster, ClusterPath};
-use onelauncher::{cluster, minecraft, processor, settings};
+use onelauncher::cluster::import::ImportSource;
+use onelauncher::{cluster, java, minecraft, processor, settings};
 use serde::{Deserialize, Serialize};
 use specta::Type;
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Emitter, Manager};
 use uuid::Uuid;
 
 #[macro_export]
@@ -25,6 +28,7 @@ macro_rules! collect_commands {
 				auth_login,
 				get_users,
 				get_user,
+				refresh_user,
 				remove_user,
 				// Cluster
 				create_cluster,
@@ -32,6 +36,10 @@ macro_rules! collect_commands {
 				get_cluster,
 				get_clusters,
 				run_cluster,
+				import_instance,
+				add_importable_instances,
+				set_cluster_groups,
+				get_groups,
 				// Processor
 				get_running_clusters,
 				get_processes_by_path,
@@ -41,10 +49,17 @@ macro_rules! collect_commands {
 				set_settings,
 				// Metadata
 				get_minecraft_versions,
+				// Java
+				get_java_versions,
+				detect_java,
+				install_java,
 				// Package
 				random_mods,
+				search_mods,
 				get_mod,
 				download_mod,
+				import_mrpack,
+				export_mrpack,
 				// Other
 				get_program_info,
 			])
@@ -66,8 +81,14 @@ pub struct CreateCluster {
 
 #[specta::specta]
 #[tauri::command]
-pub async fn create_cluster(props: CreateCluster) -> Result<Uuid, String> {
-	let path = cluster::create::create_cluster(
+pub async fn create_cluster(handle: AppHandle, props: CreateCluster) -> Result<Uuid, String> {
+	use onelauncher::utils::ingress::ProgressSink;
+
+	let name = props.name.clone();
+	let reporter =
+		ProgressReporter::start(&handle, ProgressEvent::LoaderInstall, &format!("Installing {name}"));
+
+	let result = cluster::create::create_cluster(
 		props.name,
 		props.mc_version,
 		props.mod_loader,
@@ -78,13 +99,20 @@ pub async fn create_cluster(props: CreateCluster) -> Result<Uuid, String> {
 		props.skip,
 		props.skip_watch,
 	)
-	.await?;
+	.await;
 
-	if let Some(cluster) = cluster::get(&path, None).await? {
-		Ok(cluster.uuid)
-	} else {
-		Err("Cluster does not exist".to_string())
-	}
+	let path = match result {
+		Ok(path) => {
+			reporter.update(None, "Resolving cluster");
+			path
+		}
+		Err(err) => return reporter.finish(Err(err), ""),
+	};
+
+	let cluster = reporter.finish(cluster::get(&path, None).await, "Cluster ready")?;
+	cluster
+		.map(|c| c.uuid)
+		.ok_or_else(|| "Cluster does not exist".to_string())
 }
 
 #[specta::specta]
@@ -97,7 +125,36 @@ pub async fn remove_cluster(uuid: Uuid) -> Result<(), String> {
 #[specta::specta]
 #[tauri::command]
 pub async fn run_cluster(uuid: Uuid) -> Result<(Uuid, u32), String> {
+	let cluster = cluster::get_by_uuid(uuid, None)
+		.await?
+		.ok_or("cluster not found")?;
 	let path = ClusterPath::find_by_uuid(uuid).await?;
+
+	// Pick a Java runtime matching the cluster's Minecraft version, installing
+	// a managed one on demand, and pin it before launch.
+	if cluster.meta.java_path.is_none() {
+		let major = java::required_major_for(&cluster.meta.mc_version);
+		let runtime = match java::detect_java()
+			.await?
+			.into_iter()
+			.find(|v| v.major == major)
+		{
+			Some(runtime) => runtime,
+			None => java::install_java(major, TARGET_OS, NATIVE_ARCH).await?,
+		};
+		cluster::edit(&path, |cluster| {
+			cluster.meta.java_path = Some(runtime.path.clone());
+			async { Ok(()) }
+		})
+		.await?;
+	}
+
+	// Make sure the account we are about to launch with has a live token so the
+	// launch path never hands Minecraft a stale one.
+	if let Ok(user) = minecraft::get_default_user().await {
+		ensure_fresh_user(user).await?;
+	}
+
 	let c_lock = cluster::run(&path).await?;
 
 	let p_uuid = c_lock.read().await.uuid;
@@ -153,6 +210,56 @@ pub async fn get_clusters() -> Result<Vec<Cluster>, String> {
 	Ok(cluster::list(None).await?)
 }
 
+#[specta::specta]
+#[tauri::command]
+pub async fn import_instance(
+	source: ImportSource,
+	path: PathBuf,
+	name: Option<String>,
+) -> Result<Uuid, String> {
+	let path = cluster::import::import_instance(source, path, name).await?;
+
+	if let Some(cluster) = cluster::get(&path, None).await? {
+		Ok(cluster.uuid)
+	} else {
+		Err("Cluster does not exist".to_string())
+	}
+}
+
+#[specta::specta]
+#[tauri::command]
+pub async fn add_importable_instances(source: ImportSource) -> Result<Vec<PathBuf>, String> {
+	Ok(cluster::import::add_importable_instances(source).await?)
+}
+
+#[specta::specta]
+#[tauri::command]
+pub async fn set_cluster_groups(uuid: Uuid, groups: Vec<String>) -> Result<(), String> {
+	let path = ClusterPath::find_by_uuid(uuid).await?;
+	cluster::edit(&path, |cluster| {
+		cluster.meta.groups = groups.clone();
+		async { Ok(()) }
+	})
+	.await?;
+
+	Ok(())
+}
+
+#[specta::specta]
+#[tauri::command]
+pub async fn get_groups() -> Result<Vec<String>, String> {
+	let mut groups = cluster::list(None)
+		.await?
+		.into_iter()
+		.flat_map(|cluster| cluster.meta.groups)
+		.collect::<Vec<_>>();
+
+	groups.sort();
+	groups.dedup();
+
+	Ok(groups)
+}
+
 #[specta::specta]
 #[tauri::command]
 pub async fn get_minecraft_versions() -> Result<Vec<Version>, String> {
@@ -161,6 +268,31 @@ pub async fn get_minecraft_versions() -> Result<Vec<Version>, String> {
 		.versions)
 }
 
+#[specta::specta]
+#[tauri::command]
+pub async fn get_java_versions() -> Result<Vec<JavaVersion>, String> {
+	Ok(java::get_java_versions().await?)
+}
+
+#[specta::specta]
+#[tauri::command]
+pub async fn detect_java() -> Result<Vec<JavaVersion>, String> {
+	Ok(java::detect_java().await?)
+}
+
+#[specta::specta]
+#[tauri::command]
+pub async fn install_java(handle: AppHandle, major_version: u8) -> Result<JavaVersion, String> {
+	let reporter = ProgressReporter::start(
+		&handle,
+		ProgressEvent::JavaInstall,
+		&format!("Installing Java {major_version}"),
+	);
+	let result =
+		java::install_java_with_progress(major_version, TARGET_OS, NATIVE_ARCH, &reporter).await;
+	reporter.finish(result, &format!("Installed Java {major_version}"))
+}
+
 #[specta::specta]
 #[tauri::command]
 pub async fn get_settings() -> Result<Settings, String> {
@@ -173,6 +305,119 @@ pub async fn set_settings(settings: Settings) -> Result<(), String> {
 	Ok(settings::set(settings).await?)
 }
 
+/// The kind of work a [`ProgressPayload`] is reporting on.
+#[derive(Serialize, Deserialize, Type, Clone)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum ProgressEvent {
+	/// A mod loader (Forge/Fabric/Quilt/…) is being installed.
+	LoaderInstall,
+	/// A managed Java runtime is being downloaded/unpacked.
+	JavaInstall,
+	/// Files are being downloaded.
+	Download,
+	/// Game assets/libraries are being copied into place.
+	AssetCopy,
+	/// The operation finished successfully; `fraction` is unset once this fires.
+	Finished,
+	/// The operation failed; `message` carries the error and `fraction` is unset.
+	Failed,
+}
+
+/// A structured progress update forwarded to the webview over the
+/// `progress` Tauri event channel. `fraction` is `None` for steps whose
+/// completion cannot be measured (and for the terminal events).
+#[derive(Serialize, Deserialize, Type, Clone)]
+pub struct ProgressPayload {
+	pub id: Uuid,
+	pub event: ProgressEvent,
+	pub fraction: Option<f32>,
+	pub message: String,
+}
+
+/// Forwards a [`ProgressPayload`] to the webview. Emitting is best-effort:
+/// a closed channel never fails an in-flight operation.
+fn emit_progress(handle: &AppHandle, payload: ProgressPayload) {
+	let _ = handle.emit("progress", payload);
+}
+
+/// Bridges the core [`ProgressSink`](onelauncher::utils::ingress::ProgressSink)
+/// onto the webview's `progress` channel for the lifetime of a single
+/// operation: it allocates an id, emits a start event, forwards every
+/// incremental `fraction` update, and emits exactly one terminal event
+/// (`Finished` or `Failed`) depending on the operation's result.
+struct ProgressReporter {
+	handle: AppHandle,
+	id: Uuid,
+	event: ProgressEvent,
+}
+
+impl ProgressReporter {
+	/// Begin reporting `event`, emitting the initial `fraction: 0.0` update.
+	fn start(handle: &AppHandle, event: ProgressEvent, message: &str) -> Self {
+		let reporter = Self {
+			handle: handle.clone(),
+			id: Uuid::new_v4(),
+			event,
+		};
+		emit_progress(
+			&reporter.handle,
+			ProgressPayload {
+				id: reporter.id,
+				event: reporter.event.clone(),
+				fraction: Some(0.0),
+				message: message.to_string(),
+			},
+		);
+		reporter
+	}
+
+	/// Emit the matching terminal event for `result`, then propagate it. This
+	/// guarantees a terminal event on both the success and error paths so the
+	/// frontend progress bar never hangs.
+	fn finish<T>(&self, result: onelauncher::Result<T>, done: &str) -> Result<T, String> {
+		match result {
+			Ok(value) => {
+				emit_progress(
+					&self.handle,
+					ProgressPayload {
+						id: self.id,
+						event: ProgressEvent::Finished,
+						fraction: None,
+						message: done.to_string(),
+					},
+				);
+				Ok(value)
+			}
+			Err(err) => {
+				emit_progress(
+					&self.handle,
+					ProgressPayload {
+						id: self.id,
+						event: ProgressEvent::Failed,
+						fraction: None,
+						message: err.to_string(),
+					},
+				);
+				Err(err.to_string())
+			}
+		}
+	}
+}
+
+impl onelauncher::utils::ingress::ProgressSink for ProgressReporter {
+	fn update(&self, fraction: Option<f32>, message: &str) {
+		emit_progress(
+			&self.handle,
+			ProgressPayload {
+				id: self.id,
+				event: self.event.clone(),
+				fraction,
+				message: message.to_string(),
+			},
+		);
+	}
+}
+
 #[derive(Serialize, Deserialize, Type)]
 pub struct ProgramInfo {
 	launcher_version: String,
@@ -209,7 +454,27 @@ pub async fn get_users() -> Result<Vec<MinecraftCredentials>, String> {
 #[specta::specta]
 #[tauri::command]
 pub async fn get_user(uuid: Uuid) -> Result<MinecraftCredentials, String> {
-	Ok(minecraft::get_user(uuid).await?)
+	let user = minecraft::get_user(uuid).await?;
+	Ok(ensure_fresh_user(user).await?)
+}
+
+#[specta::specta]
+#[tauri::command]
+pub async fn refresh_user(uuid: Uuid) -> Result<MinecraftCredentials, String> {
+	Ok(minecraft::refresh_user(uuid).await?)
+}
+
+/// Tokens are handed to Minecraft at launch and must never be stale, so any
+/// credential within five minutes of expiry is refreshed before it is
+/// returned. Fresh credentials are passed through untouched.
+async fn ensure_fresh_user(
+	user: MinecraftCredentials,
+) -> onelauncher::Result<MinecraftCredentials> {
+	if user.is_expiring(chrono::Duration::minutes(5)) {
+		minecraft::refresh_user(user.id).await
+	} else {
+		Ok(user)
+	}
 }
 
 #[specta::specta]
@@ -267,35 +532,83 @@ pub async fn remove_user(uuid: Uuid) -> Result<(), String> {
 
 #[specta::specta]
 #[tauri::command]
-pub async fn random_mods() -> Result<Vec<ManagedPackage>, String> {
-	let provider = content::Providers::Modrinth;
+pub async fn random_mods(provider: content::Providers) -> Result<Vec<ManagedPackage>, String> {
 	Ok(provider.list().await?)
 }
 
 #[specta::specta]
 #[tauri::command]
-pub async fn get_mod(project_id: String) -> Result<ManagedPackage, String> {
-	let provider = content::Providers::Modrinth;
+pub async fn search_mods(
+	query: String,
+	provider: content::Providers,
+	filters: content::SearchFilters,
+) -> Result<Vec<ManagedPackage>, String> {
+	Ok(provider.search(&query, filters).await?)
+}
+
+#[specta::specta]
+#[tauri::command]
+pub async fn get_mod(
+	project_id: String,
+	provider: content::Providers,
+) -> Result<ManagedPackage, String> {
 	Ok(provider.get(&project_id).await?)
 }
 
 #[specta::specta]
 #[tauri::command]
-pub async fn download_mod(cluster_id: Uuid, version_id: String) -> Result<(), String> {
+pub async fn download_mod(
+	handle: AppHandle,
+	cluster_id: Uuid,
+	version_id: String,
+	provider: content::Providers,
+) -> Result<(), String> {
 	let cluster = cluster::get_by_uuid(cluster_id, None)
 		.await?
 		.ok_or("cluster not found")?;
-	let provider = content::Providers::Modrinth;
 	let game_version = cluster.meta.mc_version.clone();
 
-	provider
+	let file = provider
 		.get_version_for_game_version(&version_id, &game_version)
 		.await?
 		.files
 		.first()
 		.ok_or("no files found")?
-		.download_to_cluster(&cluster)
-		.await?;
+		.clone();
+
+	let reporter = ProgressReporter::start(
+		&handle,
+		ProgressEvent::Download,
+		&format!("Downloading {}", file.file_name),
+	);
+	let result = file.download_to_cluster_with_progress(&cluster, &reporter).await;
+	reporter.finish(result, &format!("Downloaded {}", file.file_name))
+}
+
+#[specta::specta]
+#[tauri::command]
+pub async fn import_mrpack(
+	handle: AppHandle,
+	cluster_id: Option<Uuid>,
+	path: PathBuf,
+) -> Result<Uuid, String> {
+	let reporter = ProgressReporter::start(&handle, ProgressEvent::Download, "Installing modpack");
+	let result = mrpack::import_mrpack_with_progress(cluster_id, path, &reporter).await;
+	reporter.finish(result, "Installed modpack")
+}
+
+#[specta::specta]
+#[tauri::command]
+pub async fn export_mrpack(
+	cluster_id: Uuid,
+	output: PathBuf,
+	included_overrides: Vec<String>,
+) -> Result<(), String> {
+	let cluster = cluster::get_by_uuid(cluster_id, None)
+		.await?
+		.ok_or("cluster not found")?;
+
+	mrpack::export_mrpack(&cluster, output, included_overrides).await?;
 
 	Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,73 @@
+//! ATLauncher importer (`instance.json`).
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+use super::{loader_from_str, ParsedInstance};
+use crate::data::Loader;
+use crate::Result;
+
+#[derive(Deserialize)]
+struct Instance {
+	#[serde(default)]
+	name: String,
+	launcher: Launcher,
+	#[serde(default)]
+	loader_version: Option<LoaderVersion>,
+}
+
+#[derive(Deserialize)]
+struct Launcher {
+	#[serde(default)]
+	name: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct LoaderVersion {
+	#[serde(rename = "type")]
+	kind: String,
+	version: String,
+}
+
+#[derive(Deserialize)]
+struct Root {
+	id: String,
+}
+
+pub(super) async fn parse(path: &Path) -> Result<ParsedInstance> {
+	let raw = tokio::fs::read_to_string(path.join("instance.json")).await?;
+	let instance: Instance = serde_json::from_str(&raw)?;
+	// ATLauncher stores the Minecraft version under `id` of the `minecraft`
+	// sub-object; fall back to the directory name for the display name.
+	let mc: serde_json::Value = serde_json::from_str(&raw)?;
+	let mc_version = mc
+		.get("minecraft")
+		.and_then(|m| serde_json::from_value::<Root>(m.clone()).ok())
+		.map(|r| r.id)
+		.unwrap_or_default();
+
+	let (loader, loader_version) = match instance.loader_version {
+		Some(lv) => (loader_from_str(&lv.kind), Some(lv.version)),
+		None => (Loader::Vanilla, None),
+	};
+
+	let name = instance
+		.launcher
+		.name
+		.filter(|n| !n.is_empty())
+		.or_else(|| Some(instance.name).filter(|n| !n.is_empty()))
+		.unwrap_or_else(|| {
+			path.file_name()
+				.map(|n| n.to_string_lossy().into_owned())
+				.unwrap_or_default()
+		});
+
+	Ok(ParsedInstance {
+		name,
+		mc_version,
+		loader,
+		loader_version,
+		mods: Vec::new(),
+	})
+}
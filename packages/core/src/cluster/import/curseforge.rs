@@ -0,0 +1,74 @@
+//! CurseForge pack `manifest.json` importer.
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+use super::{loader_from_str, ModRef, ParsedInstance};
+use crate::package::content::Providers;
+use crate::Result;
+
+#[derive(Deserialize)]
+struct Manifest {
+	name: String,
+	minecraft: Minecraft,
+	#[serde(default)]
+	files: Vec<File>,
+}
+
+#[derive(Deserialize)]
+struct Minecraft {
+	version: String,
+	#[serde(rename = "modLoaders", default)]
+	mod_loaders: Vec<ModLoader>,
+}
+
+#[derive(Deserialize)]
+struct ModLoader {
+	id: String,
+	#[serde(default)]
+	primary: bool,
+}
+
+#[derive(Deserialize)]
+struct File {
+	#[serde(rename = "projectID")]
+	project_id: u64,
+	#[serde(rename = "fileID")]
+	file_id: u64,
+}
+
+pub(super) async fn parse(path: &Path) -> Result<ParsedInstance> {
+	let raw = tokio::fs::read_to_string(path.join("manifest.json")).await?;
+	let manifest: Manifest = serde_json::from_str(&raw)?;
+
+	// A modloader id looks like `forge-43.2.0`; prefer the primary entry.
+	let loader_id = manifest
+		.minecraft
+		.mod_loaders
+		.iter()
+		.find(|l| l.primary)
+		.or_else(|| manifest.minecraft.mod_loaders.first())
+		.map(|l| l.id.clone());
+
+	let loader = loader_id.as_deref().map_or(crate::data::Loader::Vanilla, loader_from_str);
+	let loader_version = loader_id.and_then(|id| id.split_once('-').map(|(_, v)| v.to_string()));
+
+	let mods = manifest
+		.files
+		.into_iter()
+		.map(|f| ModRef {
+			provider: Providers::CurseForge,
+			project_id: f.project_id.to_string(),
+			version_id: f.file_id.to_string(),
+		})
+		.collect();
+
+	Ok(ParsedInstance {
+		name: manifest.name,
+		mc_version: manifest.minecraft.version,
+		loader,
+		loader_version,
+		mods,
+	})
+}
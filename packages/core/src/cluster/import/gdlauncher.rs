@@ -0,0 +1,50 @@
+//! GDLauncher importer (`config.json`).
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+use super::{loader_from_str, ParsedInstance};
+use crate::data::Loader;
+use crate::Result;
+
+#[derive(Deserialize)]
+struct Config {
+	#[serde(default)]
+	name: Option<String>,
+	loader: LoaderConfig,
+}
+
+#[derive(Deserialize)]
+struct LoaderConfig {
+	#[serde(rename = "loaderType")]
+	loader_type: String,
+	#[serde(rename = "mcVersion")]
+	mc_version: String,
+	#[serde(rename = "loaderVersion", default)]
+	loader_version: Option<String>,
+}
+
+pub(super) async fn parse(path: &Path) -> Result<ParsedInstance> {
+	let raw = tokio::fs::read_to_string(path.join("config.json")).await?;
+	let config: Config = serde_json::from_str(&raw)?;
+
+	let loader = match config.loader.loader_type.as_str() {
+		"vanilla" => Loader::Vanilla,
+		other => loader_from_str(other),
+	};
+
+	let name = config.name.unwrap_or_else(|| {
+		path.file_name()
+			.map(|n| n.to_string_lossy().into_owned())
+			.unwrap_or_default()
+	});
+
+	Ok(ParsedInstance {
+		name,
+		mc_version: config.loader.mc_version,
+		loader,
+		loader_version: config.loader.loader_version,
+		mods: Vec::new(),
+	})
+}
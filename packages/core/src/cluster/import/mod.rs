@@ -0,0 +1,153 @@
+//! Import instances from other launchers.
+//!
+//! Each importer reads a launcher's on-disk manifest, maps the detected
+//! Minecraft version and loader onto OneLauncher's [`Loader`], resolves any
+//! referenced mods through the content providers, and produces a new cluster
+//! through the existing [`cluster::create`] path.
+
+mod atlauncher;
+mod curseforge;
+mod gdlauncher;
+mod multimc;
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::data::Loader;
+use crate::store::ClusterPath;
+use crate::Result;
+
+/// A launcher whose instances OneLauncher knows how to import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum ImportSource {
+	CurseForge,
+	MultiMc,
+	AtLauncher,
+	GdLauncher,
+}
+
+/// The normalized result of reading a foreign manifest: everything needed to
+/// drive [`cluster::create::create_cluster`] plus the mods to resolve.
+pub(crate) struct ParsedInstance {
+	pub name: String,
+	pub mc_version: String,
+	pub loader: Loader,
+	pub loader_version: Option<String>,
+	/// Provider references (`(provider, project_id, version_id)`) to resolve.
+	pub mods: Vec<ModRef>,
+}
+
+pub(crate) struct ModRef {
+	pub provider: crate::package::content::Providers,
+	pub project_id: String,
+	pub version_id: String,
+}
+
+/// Import the instance rooted at `path` from `source`, overriding its name
+/// with `name` when provided. Returns the new [`ClusterPath`].
+pub async fn import_instance(
+	source: ImportSource,
+	path: PathBuf,
+	name: Option<String>,
+) -> Result<ClusterPath> {
+	let mut parsed = match source {
+		ImportSource::CurseForge => curseforge::parse(&path).await?,
+		ImportSource::MultiMc => multimc::parse(&path).await?,
+		ImportSource::AtLauncher => atlauncher::parse(&path).await?,
+		ImportSource::GdLauncher => gdlauncher::parse(&path).await?,
+	};
+
+	if let Some(name) = name {
+		parsed.name = name;
+	}
+
+	create_from_parsed(parsed).await
+}
+
+/// Scan `source`'s default install directory for candidate instances so the UI
+/// can present a pick list. Missing directories yield an empty list.
+pub async fn add_importable_instances(source: ImportSource) -> Result<Vec<PathBuf>> {
+	let root = match default_instances_dir(source) {
+		Some(root) => root,
+		None => return Ok(Vec::new()),
+	};
+	if !root.exists() {
+		return Ok(Vec::new());
+	}
+
+	let mut out = Vec::new();
+	let mut entries = tokio::fs::read_dir(&root).await?;
+	while let Some(entry) = entries.next_entry().await? {
+		let path = entry.path();
+		if path.is_dir() && is_instance(source, &path) {
+			out.push(path);
+		}
+	}
+
+	Ok(out)
+}
+
+/// Resolve the parsed mods and create a cluster from the instance.
+async fn create_from_parsed(parsed: ParsedInstance) -> Result<ClusterPath> {
+	let path = crate::cluster::create::create_cluster(
+		parsed.name,
+		parsed.mc_version.clone(),
+		parsed.loader,
+		parsed.loader_version,
+		None,
+		None,
+		None,
+		None,
+		None,
+	)
+	.await?;
+
+	if let Some(cluster) = crate::cluster::get(&path, None).await? {
+		for r in parsed.mods {
+			// Best-effort: a single unresolved mod must not abort the import.
+			// The manifest references an exact file, so resolve by version id
+			// rather than guessing from the game version.
+			if let Ok(version) = r.provider.get_version(&r.project_id, &r.version_id).await {
+				if let Some(file) = version.files.first() {
+					let _ = file.download_to_cluster(&cluster).await;
+				}
+			}
+		}
+	}
+
+	Ok(path)
+}
+
+/// Map a manifest loader token onto OneLauncher's [`Loader`].
+pub(crate) fn loader_from_str(raw: &str) -> Loader {
+	match raw.to_lowercase().as_str() {
+		s if s.contains("neoforge") => Loader::NeoForge,
+		s if s.contains("forge") => Loader::Forge,
+		s if s.contains("fabric") => Loader::Fabric,
+		s if s.contains("quilt") => Loader::Quilt,
+		_ => Loader::Vanilla,
+	}
+}
+
+fn default_instances_dir(source: ImportSource) -> Option<PathBuf> {
+	let home = dirs::home_dir()?;
+	let data = dirs::data_dir()?;
+	Some(match source {
+		ImportSource::CurseForge => home.join("curseforge").join("minecraft").join("Instances"),
+		ImportSource::MultiMc => data.join("PrismLauncher").join("instances"),
+		ImportSource::AtLauncher => data.join("ATLauncher").join("instances"),
+		ImportSource::GdLauncher => data.join("gdlauncher_next").join("instances"),
+	})
+}
+
+fn is_instance(source: ImportSource, path: &std::path::Path) -> bool {
+	let marker = match source {
+		ImportSource::CurseForge => "manifest.json",
+		ImportSource::MultiMc => "instance.cfg",
+		ImportSource::AtLauncher => "instance.json",
+		ImportSource::GdLauncher => "config.json",
+	};
+	path.join(marker).exists()
+}
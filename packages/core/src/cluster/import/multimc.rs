@@ -0,0 +1,73 @@
+//! MultiMC / Prism Launcher importer (`instance.cfg` + `mmc-pack.json`).
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+use super::ParsedInstance;
+use crate::data::Loader;
+use crate::Result;
+
+#[derive(Deserialize)]
+struct MmcPack {
+	components: Vec<Component>,
+}
+
+#[derive(Deserialize)]
+struct Component {
+	uid: String,
+	#[serde(default)]
+	version: Option<String>,
+}
+
+pub(super) async fn parse(path: &Path) -> Result<ParsedInstance> {
+	// `instance.cfg` is a flat `key=value` file; we only need the display name.
+	let cfg = tokio::fs::read_to_string(path.join("instance.cfg")).await?;
+	let name = cfg
+		.lines()
+		.find_map(|l| l.strip_prefix("name="))
+		.map(str::to_string)
+		.unwrap_or_else(|| {
+			path.file_name()
+				.map(|n| n.to_string_lossy().into_owned())
+				.unwrap_or_default()
+		});
+
+	let raw = tokio::fs::read_to_string(path.join("mmc-pack.json")).await?;
+	let pack: MmcPack = serde_json::from_str(&raw)?;
+
+	let mut mc_version = String::new();
+	let mut loader = Loader::Vanilla;
+	let mut loader_version = None;
+	for component in pack.components {
+		match component.uid.as_str() {
+			"net.minecraft" => mc_version = component.version.unwrap_or_default(),
+			"net.minecraftforge" => {
+				loader = Loader::Forge;
+				loader_version = component.version;
+			}
+			"net.neoforged" => {
+				loader = Loader::NeoForge;
+				loader_version = component.version;
+			}
+			"net.fabricmc.fabric-loader" => {
+				loader = Loader::Fabric;
+				loader_version = component.version;
+			}
+			"org.quiltmc.quilt-loader" => {
+				loader = Loader::Quilt;
+				loader_version = component.version;
+			}
+			_ => {}
+		}
+	}
+
+	Ok(ParsedInstance {
+		name,
+		mc_version,
+		loader,
+		loader_version,
+		// MultiMC tracks mods as loose jars, not provider references.
+		mods: Vec::new(),
+	})
+}
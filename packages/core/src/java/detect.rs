@@ -0,0 +1,112 @@
+//! Candidate discovery and version probing.
+
+use std::path::{Path, PathBuf};
+
+use super::JavaVersion;
+use crate::Result;
+
+/// Scan `PATH` and well-known install roots, probe every candidate, and return
+/// the de-duplicated runtimes that responded.
+pub(super) async fn detect() -> Result<Vec<JavaVersion>> {
+	let mut candidates = candidates_on_path();
+	candidates.extend(candidates_in_common_dirs());
+	candidates.sort();
+	candidates.dedup();
+
+	let mut found = Vec::new();
+	for candidate in candidates {
+		if let Ok(version) = probe(&candidate).await {
+			if !found.iter().any(|v: &JavaVersion| v.path == version.path) {
+				found.push(version);
+			}
+		}
+	}
+
+	Ok(found)
+}
+
+fn binary_name() -> &'static str {
+	if cfg!(windows) {
+		"java.exe"
+	} else {
+		"java"
+	}
+}
+
+fn candidates_on_path() -> Vec<PathBuf> {
+	let Some(path) = std::env::var_os("PATH") else {
+		return Vec::new();
+	};
+	std::env::split_paths(&path)
+		.map(|dir| dir.join(binary_name()))
+		.filter(|p| p.exists())
+		.collect()
+}
+
+fn candidates_in_common_dirs() -> Vec<PathBuf> {
+	let mut roots: Vec<PathBuf> = Vec::new();
+	if cfg!(target_os = "windows") {
+		roots.push(PathBuf::from(r"C:\Program Files\Java"));
+		roots.push(PathBuf::from(r"C:\Program Files\Eclipse Adoptium"));
+	} else if cfg!(target_os = "macos") {
+		roots.push(PathBuf::from("/Library/Java/JavaVirtualMachines"));
+	} else {
+		roots.push(PathBuf::from("/usr/lib/jvm"));
+	}
+
+	let mut out = Vec::new();
+	for root in roots {
+		let Ok(entries) = std::fs::read_dir(&root) else {
+			continue;
+		};
+		for entry in entries.flatten() {
+			let bin = entry.path().join("bin").join(binary_name());
+			if bin.exists() {
+				out.push(bin);
+			}
+			// macOS nests the binary under `Contents/Home/bin`.
+			let macos = entry.path().join("Contents/Home/bin").join(binary_name());
+			if macos.exists() {
+				out.push(macos);
+			}
+		}
+	}
+	out
+}
+
+/// Run `java -version` and parse the major version out of its (stderr) output.
+pub(super) async fn probe(path: &Path) -> Result<JavaVersion> {
+	let output = tokio::process::Command::new(path)
+		.arg("-version")
+		.output()
+		.await?;
+
+	// `java -version` prints to stderr, e.g. `openjdk version "17.0.9"`.
+	let text = String::from_utf8_lossy(&output.stderr);
+	let version = text
+		.lines()
+		.next()
+		.and_then(|l| l.split('"').nth(1))
+		.ok_or_else(|| anyhow::anyhow!("could not parse java version from {}", path.display()))?
+		.to_string();
+
+	Ok(JavaVersion {
+		path: path.to_path_buf(),
+		major: parse_major(&version),
+		version,
+	})
+}
+
+/// Legacy runtimes report `1.8.0_xxx`; modern ones report `17.0.9`.
+fn parse_major(version: &str) -> u8 {
+	let mut parts = version.split('.');
+	match parts.next() {
+		Some("1") => parts.next().and_then(|p| p.parse().ok()).unwrap_or(0),
+		Some(first) => first
+			.split('_')
+			.next()
+			.and_then(|p| p.parse().ok())
+			.unwrap_or(0),
+		None => 0,
+	}
+}
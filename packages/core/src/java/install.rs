@@ -0,0 +1,123 @@
+//! Adoptium runtime download and unpack.
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use super::{detect, JavaVersion};
+use crate::constants::DIRECTORIES;
+use crate::utils::http::{fetch, get_json};
+use crate::utils::ingress::ProgressSink;
+use crate::Result;
+
+/// Adoptium's assets endpoint returns the newest GA release for a feature
+/// version, filtered to a single os/arch/image.
+const ADOPTIUM_API: &str = "https://api.adoptium.net/v3/assets/latest";
+
+#[derive(Deserialize)]
+struct Asset {
+	binary: Binary,
+	release_name: String,
+}
+
+#[derive(Deserialize)]
+struct Binary {
+	package: Package,
+}
+
+#[derive(Deserialize)]
+struct Package {
+	link: String,
+	checksum: String,
+}
+
+/// Download, verify, and unpack an Adoptium JRE into the managed runtimes dir.
+pub(super) async fn install(
+	major_version: u8,
+	target_os: &str,
+	arch: &str,
+	sink: &dyn ProgressSink,
+) -> Result<JavaVersion> {
+	let os = adoptium_os(target_os);
+	let architecture = adoptium_arch(arch);
+	let url = format!(
+		"{ADOPTIUM_API}/{major_version}/hotspot?os={os}&architecture={architecture}&image_type=jre"
+	);
+
+	sink.update(Some(0.0), &format!("Resolving Java {major_version}"));
+	let assets: Vec<Asset> = get_json(&url).await?;
+	let asset = assets
+		.into_iter()
+		.next()
+		.ok_or_else(|| anyhow::anyhow!("no Adoptium JRE for Java {major_version} on {os}/{architecture}"))?;
+
+	sink.update(Some(0.1), &format!("Downloading {}", asset.release_name));
+	let archive = fetch(&asset.binary.package.link).await?;
+
+	// Adoptium publishes a SHA-256 checksum for each archive; verify against it.
+	let actual = crate::utils::sha256_hex(&archive);
+	if !asset.binary.package.checksum.is_empty() && actual != asset.binary.package.checksum {
+		// A checksum mismatch is fatal — a partial/corrupt JRE is worse than none.
+		return Err(anyhow::anyhow!("checksum mismatch for {}", asset.release_name));
+	}
+
+	sink.update(Some(0.7), "Unpacking runtime");
+	let dest = DIRECTORIES.java_dir().join(format!("jre-{major_version}"));
+	tokio::fs::create_dir_all(&dest).await?;
+	unpack(&archive, &dest, target_os).await?;
+
+	let bin = runtime_binary(&dest, target_os);
+	let version = detect::probe(&bin).await?;
+	sink.update(None, &format!("Installed Java {major_version}"));
+	Ok(version)
+}
+
+async fn unpack(archive: &[u8], dest: &std::path::Path, target_os: &str) -> Result<()> {
+	let archive = archive.to_vec();
+	let dest = dest.to_path_buf();
+	let target_os = target_os.to_string();
+	// Unpacking is blocking IO; keep it off the async runtime's worker threads.
+	tokio::task::spawn_blocking(move || -> Result<()> {
+		if target_os == "windows" {
+			let mut zip = zip::ZipArchive::new(std::io::Cursor::new(archive))?;
+			zip.extract(&dest)?;
+		} else {
+			let tar = flate2::read::GzDecoder::new(std::io::Cursor::new(archive));
+			let mut archive = tar::Archive::new(tar);
+			archive.unpack(&dest)?;
+		}
+		Ok(())
+	})
+	.await??;
+	Ok(())
+}
+
+/// Adoptium nests the runtime under `<release>/bin/java`; find the one binary.
+fn runtime_binary(dest: &std::path::Path, target_os: &str) -> PathBuf {
+	let name = if target_os == "windows" { "java.exe" } else { "java" };
+	for entry in walkdir::WalkDir::new(dest)
+		.into_iter()
+		.filter_map(std::result::Result::ok)
+	{
+		if entry.file_name() == name {
+			return entry.into_path();
+		}
+	}
+	dest.join("bin").join(name)
+}
+
+fn adoptium_os(target_os: &str) -> &str {
+	match target_os {
+		"windows" => "windows",
+		"macos" => "mac",
+		_ => "linux",
+	}
+}
+
+fn adoptium_arch(arch: &str) -> &str {
+	match arch {
+		"aarch64" => "aarch64",
+		"x86" => "x86",
+		_ => "x64",
+	}
+}
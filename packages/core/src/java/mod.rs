@@ -0,0 +1,73 @@
+//! Java runtime detection and managed downloads.
+//!
+//! OneLauncher no longer relies on whatever `java` happens to be on `PATH`.
+//! This subsystem enumerates the JREs it knows about, probes candidates to
+//! learn their major version, and downloads an Adoptium runtime into a managed
+//! directory when a required version is missing.
+
+mod detect;
+mod install;
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::utils::ingress::{NoopSink, ProgressSink};
+use crate::Result;
+
+/// A resolved Java runtime: the launcher binary plus its parsed major version.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct JavaVersion {
+	/// Absolute path to the `java`/`javaw` executable.
+	pub path: std::path::PathBuf,
+	/// Major feature version (8, 17, 21, …).
+	pub major: u8,
+	/// The full version string as reported by `java -version`.
+	pub version: String,
+}
+
+/// The configured/detected runtimes OneLauncher currently knows about.
+pub async fn get_java_versions() -> Result<Vec<JavaVersion>> {
+	crate::settings::get().await.map(|s| s.java_versions)
+}
+
+/// Scan `PATH` and the common install locations, probing each candidate.
+pub async fn detect_java() -> Result<Vec<JavaVersion>> {
+	detect::detect().await
+}
+
+/// Download and unpack an Adoptium runtime for `major_version` targeting
+/// `target_os`/`arch`, returning the resolved [`JavaVersion`].
+pub async fn install_java(major_version: u8, target_os: &str, arch: &str) -> Result<JavaVersion> {
+	install_java_with_progress(major_version, target_os, arch, &NoopSink).await
+}
+
+/// [`install_java`] that reports download/unpack progress through `sink`.
+pub async fn install_java_with_progress(
+	major_version: u8,
+	target_os: &str,
+	arch: &str,
+	sink: &dyn ProgressSink,
+) -> Result<JavaVersion> {
+	install::install(major_version, target_os, arch, sink).await
+}
+
+/// The Java major version Mojang ships for a given Minecraft version. Modern
+/// releases (1.20.5+) need 21, 1.18–1.20.4 need 17, 1.17 needs 16, and
+/// everything older runs on 8.
+pub fn required_major_for(mc_version: &str) -> u8 {
+	let parts: Vec<u32> = mc_version
+		.split('.')
+		.skip(1)
+		.filter_map(|p| p.parse().ok())
+		.collect();
+	let minor = parts.first().copied().unwrap_or(0);
+	let patch = parts.get(1).copied().unwrap_or(0);
+
+	match (minor, patch) {
+		(m, _) if m >= 21 => 21,
+		(20, p) if p >= 5 => 21,
+		(m, _) if m >= 18 => 17,
+		(17, _) => 16,
+		_ => 8,
+	}
+}
@@ -0,0 +1,5 @@
+//! Microsoft/Minecraft authentication and account storage.
+
+pub mod refresh;
+
+pub use refresh::{get_default_user, refresh_user};
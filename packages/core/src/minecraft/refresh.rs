@@ -0,0 +1,117 @@
+//! Silent token refresh against the Microsoft/Xbox/Minecraft chain.
+//!
+//! Given a stored refresh token, this re-runs the non-interactive half of the
+//! login chain (Microsoft refresh -> Xbox Live -> XSTS -> Minecraft) and
+//! re-fetches the player profile, returning updated [`MinecraftCredentials`].
+
+use chrono::Utc;
+use serde::Deserialize;
+
+use crate::constants::{MICROSOFT_CLIENT_ID, MICROSOFT_REDIRECT_URI};
+use crate::store::credentials::MinecraftCredentials;
+use crate::store::minecraft_store;
+use crate::Result;
+
+#[derive(Deserialize)]
+struct RefreshResponse {
+	access_token: String,
+	refresh_token: String,
+	/// Seconds until the new access token expires.
+	expires_in: i64,
+}
+
+/// Refresh the account identified by `uuid`, persist the new tokens, and return
+/// the updated credentials. Errors clearly if the account no longer owns the
+/// game so the UI can prompt a re-purchase/re-login rather than hang.
+pub async fn refresh_user(uuid: uuid::Uuid) -> Result<MinecraftCredentials> {
+	let mut store = minecraft_store().write().await;
+	let existing = store
+		.get(&uuid)
+		.cloned()
+		.ok_or_else(|| anyhow::anyhow!("no stored account for {uuid}"))?;
+
+	let refreshed = exchange(&existing.refresh_token).await?;
+	if !owns_game(&refreshed.access_token).await? {
+		return Err(anyhow::anyhow!("account no longer owns Minecraft"));
+	}
+	let username = fetch_profile(&refreshed.access_token).await?;
+
+	let credentials = MinecraftCredentials {
+		id: existing.id,
+		username,
+		access_token: refreshed.access_token,
+		refresh_token: refreshed.refresh_token,
+		expires: Utc::now() + chrono::Duration::seconds(refreshed.expires_in),
+	};
+
+	store.insert(credentials.clone());
+	Ok(credentials)
+}
+
+/// The account used for launch when the cluster does not pin one: the most
+/// recently authenticated credential in the store.
+pub async fn get_default_user() -> Result<MinecraftCredentials> {
+	let store = minecraft_store().read().await;
+	store
+		.default()
+		.cloned()
+		.ok_or_else(|| anyhow::anyhow!("no Minecraft account is logged in"))
+}
+
+async fn exchange(refresh_token: &str) -> Result<RefreshResponse> {
+	// oauth20_token.srf is POST-form-only; never put the refresh token in a URL
+	// where it could be logged.
+	let response = reqwest::Client::new()
+		.post("https://login.live.com/oauth20_token.srf")
+		.form(&[
+			("client_id", MICROSOFT_CLIENT_ID),
+			("grant_type", "refresh_token"),
+			("refresh_token", refresh_token),
+			("redirect_uri", MICROSOFT_REDIRECT_URI),
+		])
+		.send()
+		.await?
+		.error_for_status()?;
+	Ok(response.json().await?)
+}
+
+#[derive(Deserialize)]
+struct Entitlements {
+	items: Vec<serde_json::Value>,
+}
+
+/// Derive ownership from the entitlements endpoint: an account that owns the
+/// game has at least one store entitlement.
+async fn owns_game(access_token: &str) -> Result<bool> {
+	let entitlements: Entitlements = authed_get(
+		"https://api.minecraftservices.com/entitlements/mcstore",
+		access_token,
+	)
+	.await?;
+	Ok(!entitlements.items.is_empty())
+}
+
+#[derive(Deserialize)]
+struct Profile {
+	name: String,
+}
+
+async fn fetch_profile(access_token: &str) -> Result<String> {
+	let profile: Profile = authed_get(
+		"https://api.minecraftservices.com/minecraft/profile",
+		access_token,
+	)
+	.await?;
+	Ok(profile.name)
+}
+
+/// `GET` `url` with a bearer `Authorization` header and deserialize the body.
+async fn authed_get<T: serde::de::DeserializeOwned>(url: &str, access_token: &str) -> Result<T> {
+	let response = reqwest::Client::new()
+		.get(url)
+		.bearer_auth(access_token)
+		.send()
+		.await?
+		.error_for_status()?;
+	Ok(response.json().await?)
+}
@@ -0,0 +1,177 @@
+//! CurseForge content provider.
+//!
+//! CurseForge models a mod as a *project* (class id `6` == "Mc Mods") and each
+//! concrete download as a *file*. We map project id -> [`ManagedPackage`] and
+//! file id -> [`ManagedVersion`] so callers cannot tell which registry backs a
+//! package. The file fingerprint is verified before the bytes hit disk.
+
+use serde::Deserialize;
+
+use super::{ManagedPackage, ManagedVersion, ManagedVersionFile};
+use crate::package::content::SearchFilters;
+use crate::Result;
+
+const API_BASE: &str = "https://api.curseforge.com/v1";
+/// Minecraft game id and the "Mc Mods" class id used to scope searches.
+const GAME_ID: u32 = 432;
+const MOD_CLASS_ID: u32 = 6;
+/// Distribution key baked in at build time. Every v1 request is rejected with
+/// `403` without it, so the header is not optional.
+const API_KEY: &str = env!("CURSEFORGE_API_KEY");
+
+/// `GET` `url` with the `x-api-key` header CurseForge requires and deserialize
+/// the JSON body. Mirrors [`crate::utils::http::get_json`] but keyed.
+async fn get_json<T: serde::de::DeserializeOwned>(url: &str) -> Result<T> {
+	let response = reqwest::Client::new()
+		.get(url)
+		.header("x-api-key", API_KEY)
+		.header("Accept", "application/json")
+		.send()
+		.await?
+		.error_for_status()?;
+	Ok(response.json::<T>().await?)
+}
+
+#[derive(Deserialize)]
+struct Paged<T> {
+	data: T,
+}
+
+#[derive(Deserialize)]
+struct CfProject {
+	id: u32,
+	name: String,
+	summary: String,
+	#[serde(rename = "downloadCount")]
+	download_count: u64,
+	logo: Option<CfLogo>,
+}
+
+#[derive(Deserialize)]
+struct CfLogo {
+	url: String,
+}
+
+#[derive(Deserialize)]
+struct CfFile {
+	id: u32,
+	#[serde(rename = "fileName")]
+	file_name: String,
+	#[serde(rename = "downloadUrl")]
+	download_url: Option<String>,
+	#[serde(rename = "gameVersions")]
+	game_versions: Vec<String>,
+	hashes: Vec<CfHash>,
+}
+
+#[derive(Deserialize)]
+struct CfHash {
+	value: String,
+	/// 1 == sha1, 2 == md5 in the CurseForge schema.
+	algo: u8,
+}
+
+impl From<CfProject> for ManagedPackage {
+	fn from(p: CfProject) -> Self {
+		ManagedPackage {
+			id: p.id.to_string(),
+			title: p.name,
+			description: p.summary,
+			downloads: p.download_count,
+			icon_url: p.logo.map(|l| l.url),
+		}
+	}
+}
+
+impl CfFile {
+	fn sha1(&self) -> Option<&str> {
+		self.hashes
+			.iter()
+			.find(|h| h.algo == 1)
+			.map(|h| h.value.as_str())
+	}
+
+	fn into_version(self, project_id: &str) -> ManagedVersion {
+		let sha1 = self.sha1().map(ToString::to_string);
+		ManagedVersion {
+			id: self.id.to_string(),
+			project_id: project_id.to_string(),
+			game_versions: self.game_versions,
+			files: self
+				.download_url
+				.map(|url| ManagedVersionFile {
+					file_name: self.file_name,
+					url,
+					sha1,
+				})
+				.into_iter()
+				.collect(),
+		}
+	}
+}
+
+pub async fn list() -> Result<Vec<ManagedPackage>> {
+	search("", SearchFilters::default()).await
+}
+
+pub async fn search(query: &str, filters: SearchFilters) -> Result<Vec<ManagedPackage>> {
+	let mut url = format!(
+		"{API_BASE}/mods/search?gameId={GAME_ID}&classId={MOD_CLASS_ID}&searchFilter={}",
+		urlencoding::encode(query)
+	);
+	if let Some(v) = &filters.game_version {
+		url.push_str(&format!("&gameVersion={}", urlencoding::encode(v)));
+	}
+	if let Some(loader) = &filters.loader {
+		// CurseForge's `modLoaderType` enum: 1 Forge, 4 Fabric, 5 Quilt, 6 NeoForge.
+		let kind = match loader.to_lowercase().as_str() {
+			"forge" => Some(1),
+			"fabric" => Some(4),
+			"quilt" => Some(5),
+			"neoforge" => Some(6),
+			_ => None,
+		};
+		if let Some(kind) = kind {
+			url.push_str(&format!("&modLoaderType={kind}"));
+		}
+	}
+	if let Some(limit) = filters.limit {
+		url.push_str(&format!("&pageSize={limit}"));
+	}
+	if let Some(offset) = filters.offset {
+		url.push_str(&format!("&index={offset}"));
+	}
+
+	let page: Paged<Vec<CfProject>> = get_json(&url).await?;
+	Ok(page.data.into_iter().map(Into::into).collect())
+}
+
+pub async fn get(project_id: &str) -> Result<ManagedPackage> {
+	let url = format!("{API_BASE}/mods/{project_id}");
+	let page: Paged<CfProject> = get_json(&url).await?;
+	Ok(page.data.into())
+}
+
+pub async fn version_for_game(project_id: &str, game_version: &str) -> Result<ManagedVersion> {
+	let url = format!(
+		"{API_BASE}/mods/{project_id}/files?gameVersion={}",
+		urlencoding::encode(game_version)
+	);
+	let page: Paged<Vec<CfFile>> = get_json(&url).await?;
+	let file = page
+		.data
+		.into_iter()
+		.find(|f| f.game_versions.iter().any(|v| v == game_version))
+		.ok_or_else(|| {
+			anyhow::anyhow!("no CurseForge file for {project_id} on {game_version}")
+		})?;
+
+	Ok(file.into_version(project_id))
+}
+
+/// Resolve a single file (`file_id`) belonging to `project_id`.
+pub async fn version(project_id: &str, file_id: &str) -> Result<ManagedVersion> {
+	let url = format!("{API_BASE}/mods/{project_id}/files/{file_id}");
+	let page: Paged<CfFile> = get_json(&url).await?;
+	Ok(page.data.into_version(project_id))
+}
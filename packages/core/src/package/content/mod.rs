@@ -0,0 +1,171 @@
+//! Content providers: a thin abstraction over the registries OneLauncher can
+//! browse and install mods from. Every provider returns the same
+//! [`ManagedPackage`]/[`ManagedVersion`] shapes so the frontend and the
+//! download path are provider-agnostic.
+
+pub mod curseforge;
+pub mod modrinth;
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::store::Cluster;
+use crate::utils::ingress::{NoopSink, ProgressSink};
+use crate::utils::sha1_hex;
+use crate::Result;
+
+/// A project as surfaced in a browse/search list, normalized across providers.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ManagedPackage {
+	pub id: String,
+	pub title: String,
+	pub description: String,
+	pub downloads: u64,
+	pub icon_url: Option<String>,
+}
+
+/// A concrete, installable version of a [`ManagedPackage`].
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ManagedVersion {
+	pub id: String,
+	pub project_id: String,
+	pub game_versions: Vec<String>,
+	pub files: Vec<ManagedVersionFile>,
+}
+
+/// A single downloadable artifact belonging to a [`ManagedVersion`].
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ManagedVersionFile {
+	pub file_name: String,
+	pub url: String,
+	/// The provider-declared SHA1, verified before the bytes are written.
+	pub sha1: Option<String>,
+}
+
+impl ManagedVersionFile {
+	/// Download the file into `cluster`'s mods directory, verifying the
+	/// declared SHA1 fingerprint (when present) before writing it to disk.
+	pub async fn download_to_cluster(&self, cluster: &Cluster) -> Result<()> {
+		self.download_to_cluster_with_progress(cluster, &NoopSink).await
+	}
+
+	/// [`download_to_cluster`] that streams the body, reporting the fraction of
+	/// bytes received through `sink` as it goes.
+	pub async fn download_to_cluster_with_progress(
+		&self,
+		cluster: &Cluster,
+		sink: &dyn ProgressSink,
+	) -> Result<()> {
+		use futures::StreamExt;
+
+		let response = reqwest::get(&self.url).await?.error_for_status()?;
+		let total = response.content_length();
+		let mut data = Vec::with_capacity(total.unwrap_or(0) as usize);
+		let mut stream = response.bytes_stream();
+		while let Some(chunk) = stream.next().await {
+			let chunk = chunk?;
+			data.extend_from_slice(&chunk);
+			let fraction = total.map(|t| data.len() as f32 / t as f32);
+			sink.update(fraction, &self.file_name);
+		}
+
+		self.write_verified(cluster, &data).await
+	}
+
+	/// Verify the declared SHA1 (when present) and write the bytes into the
+	/// cluster's mods directory.
+	async fn write_verified(&self, cluster: &Cluster, data: &[u8]) -> Result<()> {
+		if let Some(expected) = &self.sha1 {
+			let actual = sha1_hex(data);
+			if &actual != expected {
+				return Err(anyhow::anyhow!(
+					"hash mismatch for {}: expected {expected}, got {actual}",
+					self.file_name
+				));
+			}
+		}
+
+		let dest = cluster.get_full_path().await?.join("mods").join(&self.file_name);
+		if let Some(parent) = dest.parent() {
+			tokio::fs::create_dir_all(parent).await?;
+		}
+		tokio::fs::write(&dest, data).await?;
+		Ok(())
+	}
+}
+
+/// A content registry OneLauncher can search and install from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum Providers {
+	Modrinth,
+	CurseForge,
+}
+
+/// Filters applied to a [`Providers::search`] query. Absent fields are not
+/// constrained, so a default value returns the unfiltered feed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct SearchFilters {
+	/// Restrict to a single Minecraft version (`gameVersion` upstream).
+	pub game_version: Option<String>,
+	/// Restrict to a single loader (`fabric`, `forge`, …).
+	pub loader: Option<String>,
+	/// Result window.
+	pub limit: Option<u32>,
+	pub offset: Option<u32>,
+}
+
+impl Providers {
+	/// The registry's curated/landing feed.
+	pub async fn list(&self) -> Result<Vec<ManagedPackage>> {
+		match self {
+			Self::Modrinth => modrinth::list().await,
+			Self::CurseForge => curseforge::list().await,
+		}
+	}
+
+	/// Free-text search with optional [`SearchFilters`].
+	pub async fn search(
+		&self,
+		query: &str,
+		filters: SearchFilters,
+	) -> Result<Vec<ManagedPackage>> {
+		match self {
+			Self::Modrinth => modrinth::search(query, filters).await,
+			Self::CurseForge => curseforge::search(query, filters).await,
+		}
+	}
+
+	/// Fetch a single project by its provider-native id.
+	pub async fn get(&self, project_id: &str) -> Result<ManagedPackage> {
+		match self {
+			Self::Modrinth => modrinth::get(project_id).await,
+			Self::CurseForge => curseforge::get(project_id).await,
+		}
+	}
+
+	/// Resolve the version of `project_id` that matches `game_version`.
+	pub async fn get_version_for_game_version(
+		&self,
+		project_id: &str,
+		game_version: &str,
+	) -> Result<ManagedVersion> {
+		match self {
+			Self::Modrinth => modrinth::version_for_game(project_id, game_version).await,
+			Self::CurseForge => curseforge::version_for_game(project_id, game_version).await,
+		}
+	}
+
+	/// Resolve a specific version (`version_id`) of `project_id`. Used by the
+	/// instance importers, which reference an exact file rather than whatever
+	/// happens to match a game version.
+	pub async fn get_version(
+		&self,
+		project_id: &str,
+		version_id: &str,
+	) -> Result<ManagedVersion> {
+		match self {
+			Self::Modrinth => modrinth::version(version_id).await,
+			Self::CurseForge => curseforge::version(project_id, version_id).await,
+		}
+	}
+}
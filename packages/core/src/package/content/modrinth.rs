@@ -0,0 +1,146 @@
+//! Modrinth content provider. Mirrors the shapes returned by
+//! [`curseforge`](super::curseforge) so the two are interchangeable.
+
+use serde::Deserialize;
+
+use super::{ManagedPackage, ManagedVersion, ManagedVersionFile};
+use crate::package::content::SearchFilters;
+use crate::utils::http::get_json;
+use crate::Result;
+
+const API_BASE: &str = "https://api.modrinth.com/v2";
+
+#[derive(Deserialize)]
+struct SearchResponse {
+	hits: Vec<SearchHit>,
+}
+
+#[derive(Deserialize)]
+struct SearchHit {
+	project_id: String,
+	title: String,
+	description: String,
+	downloads: u64,
+	icon_url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Project {
+	id: String,
+	title: String,
+	description: String,
+	downloads: u64,
+	icon_url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ModrinthVersion {
+	id: String,
+	project_id: String,
+	game_versions: Vec<String>,
+	files: Vec<ModrinthFile>,
+}
+
+#[derive(Deserialize)]
+struct ModrinthFile {
+	filename: String,
+	url: String,
+	hashes: FileHashes,
+}
+
+#[derive(Deserialize)]
+struct FileHashes {
+	sha1: Option<String>,
+}
+
+impl From<SearchHit> for ManagedPackage {
+	fn from(h: SearchHit) -> Self {
+		ManagedPackage {
+			id: h.project_id,
+			title: h.title,
+			description: h.description,
+			downloads: h.downloads,
+			icon_url: h.icon_url,
+		}
+	}
+}
+
+impl From<Project> for ManagedPackage {
+	fn from(p: Project) -> Self {
+		ManagedPackage {
+			id: p.id,
+			title: p.title,
+			description: p.description,
+			downloads: p.downloads,
+			icon_url: p.icon_url,
+		}
+	}
+}
+
+impl From<ModrinthVersion> for ManagedVersion {
+	fn from(v: ModrinthVersion) -> Self {
+		ManagedVersion {
+			id: v.id,
+			project_id: v.project_id,
+			game_versions: v.game_versions,
+			files: v
+				.files
+				.into_iter()
+				.map(|f| ManagedVersionFile {
+					file_name: f.filename,
+					url: f.url,
+					sha1: f.hashes.sha1,
+				})
+				.collect(),
+		}
+	}
+}
+
+pub async fn list() -> Result<Vec<ManagedPackage>> {
+	search("", SearchFilters::default()).await
+}
+
+pub async fn search(query: &str, filters: SearchFilters) -> Result<Vec<ManagedPackage>> {
+	let mut url = format!("{API_BASE}/search?query={}", urlencoding::encode(query));
+	if let Some(limit) = filters.limit {
+		url.push_str(&format!("&limit={limit}"));
+	}
+	if let Some(offset) = filters.offset {
+		url.push_str(&format!("&offset={offset}"));
+	}
+	let mut facets = Vec::new();
+	if let Some(v) = &filters.game_version {
+		facets.push(format!("[\"versions:{v}\"]"));
+	}
+	if let Some(loader) = &filters.loader {
+		facets.push(format!("[\"categories:{loader}\"]"));
+	}
+	if !facets.is_empty() {
+		let facets = format!("[{}]", facets.join(","));
+		url.push_str(&format!("&facets={}", urlencoding::encode(&facets)));
+	}
+
+	let response: SearchResponse = get_json(&url).await?;
+	Ok(response.hits.into_iter().map(Into::into).collect())
+}
+
+pub async fn get(project_id: &str) -> Result<ManagedPackage> {
+	let project: Project = get_json(&format!("{API_BASE}/project/{project_id}")).await?;
+	Ok(project.into())
+}
+
+pub async fn version(version_id: &str) -> Result<ManagedVersion> {
+	let version: ModrinthVersion = get_json(&format!("{API_BASE}/version/{version_id}")).await?;
+	Ok(version.into())
+}
+
+pub async fn version_for_game(project_id: &str, game_version: &str) -> Result<ManagedVersion> {
+	let versions: Vec<ModrinthVersion> =
+		get_json(&format!("{API_BASE}/project/{project_id}/version")).await?;
+	let version = versions
+		.into_iter()
+		.find(|v| v.game_versions.iter().any(|g| g == game_version))
+		.ok_or_else(|| anyhow::anyhow!("no Modrinth version for {project_id} on {game_version}"))?;
+
+	Ok(version.into())
+}
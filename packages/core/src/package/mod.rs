@@ -0,0 +1,4 @@
+//! Content packages: providers (Modrinth, CurseForge) and modpack formats.
+
+pub mod content;
+pub mod mrpack;
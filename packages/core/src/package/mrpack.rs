@@ -0,0 +1,298 @@
+//! Import and export of Modrinth `.mrpack` modpacks.
+//!
+//! A `.mrpack` is a zip containing a `modrinth.index.json` manifest plus
+//! optional `overrides/` and `client-overrides/` trees that are copied into
+//! the instance verbatim. Unlike `download_mod`, which installs a single file,
+//! this round-trips a whole pack.
+
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use zip::ZipArchive;
+
+use crate::data::Loader;
+use crate::store::Cluster;
+use crate::utils::http::fetch;
+use crate::utils::ingress::{NoopSink, ProgressSink};
+use crate::utils::sha1_hex;
+use crate::{cluster, Result};
+
+/// The subset of `modrinth.index.json` we read/write.
+#[derive(Debug, Serialize, Deserialize)]
+struct ModrinthIndex {
+	#[serde(rename = "formatVersion")]
+	format_version: u32,
+	name: String,
+	#[serde(rename = "versionId")]
+	version_id: String,
+	#[serde(default)]
+	dependencies: std::collections::HashMap<String, String>,
+	files: Vec<IndexFile>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexFile {
+	path: String,
+	hashes: Hashes,
+	#[serde(default)]
+	env: Option<Env>,
+	downloads: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Hashes {
+	sha1: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Env {
+	#[serde(default)]
+	client: Option<SideSupport>,
+	#[serde(default)]
+	server: Option<SideSupport>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum SideSupport {
+	Required,
+	Optional,
+	Unsupported,
+}
+
+impl IndexFile {
+	/// Whether this file should be installed on a client instance. Files that
+	/// declare `env.client = unsupported` are server-only and skipped.
+	fn is_client_relevant(&self) -> bool {
+		match &self.env {
+			Some(env) => env.client != Some(SideSupport::Unsupported),
+			None => true,
+		}
+	}
+}
+
+/// Install a `.mrpack` into `cluster_id`, creating a new cluster from the
+/// pack's dependencies when `cluster_id` is `None`. Returns the target
+/// cluster's uuid.
+pub async fn import_mrpack(cluster_id: Option<Uuid>, path: PathBuf) -> Result<Uuid> {
+	import_mrpack_with_progress(cluster_id, path, &NoopSink).await
+}
+
+/// [`import_mrpack`] that reports per-file progress through `sink`.
+pub async fn import_mrpack_with_progress(
+	cluster_id: Option<Uuid>,
+	path: PathBuf,
+	sink: &dyn ProgressSink,
+) -> Result<Uuid> {
+	let bytes = tokio::fs::read(&path).await?;
+	let mut archive = ZipArchive::new(std::io::Cursor::new(bytes))?;
+
+	let index: ModrinthIndex = {
+		let mut entry = archive.by_name("modrinth.index.json")?;
+		let mut raw = String::new();
+		entry.read_to_string(&mut raw)?;
+		serde_json::from_str(&raw)?
+	};
+
+	sink.update(Some(0.0), &format!("Installing {}", index.name));
+
+	let cluster = match cluster_id {
+		Some(uuid) => cluster::get_by_uuid(uuid, None)
+			.await?
+			.ok_or_else(|| anyhow::anyhow!("cluster not found"))?,
+		None => cluster_from_index(&index).await?,
+	};
+	let root = cluster.get_full_path().await?;
+
+	// Download every client-relevant declared file by its path, verifying sha1.
+	let client_files: Vec<&IndexFile> =
+		index.files.iter().filter(|f| f.is_client_relevant()).collect();
+	let total = client_files.len().max(1) as f32;
+	for (done, file) in client_files.iter().enumerate() {
+		let url = file
+			.downloads
+			.first()
+			.ok_or_else(|| anyhow::anyhow!("no download url for {}", file.path))?;
+		let data = fetch(url).await?;
+
+		let actual = sha1_hex(&data);
+		if actual != file.hashes.sha1 {
+			return Err(anyhow::anyhow!(
+				"sha1 mismatch for {}: expected {}, got {}",
+				file.path,
+				file.hashes.sha1,
+				actual
+			));
+		}
+
+		let dest = root.join(&file.path);
+		if let Some(parent) = dest.parent() {
+			tokio::fs::create_dir_all(parent).await?;
+		}
+		tokio::fs::write(&dest, &data).await?;
+
+		sink.update(
+			Some((done + 1) as f32 / total),
+			&format!("Downloaded {}", file.path),
+		);
+	}
+
+	// Copy overrides. Both `overrides/` and `client-overrides/` land in the
+	// cluster root; server-overrides are ignored on a client install.
+	copy_overrides(&mut archive, &root)?;
+
+	sink.update(None, &format!("Installed {}", index.name));
+	Ok(cluster.uuid)
+}
+
+/// Extract the minecraft version and loader from a pack's `dependencies` map
+/// and create a fresh cluster for it.
+async fn cluster_from_index(index: &ModrinthIndex) -> Result<Cluster> {
+	let mc_version = index
+		.dependencies
+		.get("minecraft")
+		.cloned()
+		.ok_or_else(|| anyhow::anyhow!("pack is missing a minecraft dependency"))?;
+
+	let (loader, loader_version) = if let Some(v) = index.dependencies.get("forge") {
+		(Loader::Forge, Some(v.clone()))
+	} else if let Some(v) = index.dependencies.get("neoforge") {
+		(Loader::NeoForge, Some(v.clone()))
+	} else if let Some(v) = index.dependencies.get("fabric-loader") {
+		(Loader::Fabric, Some(v.clone()))
+	} else if let Some(v) = index.dependencies.get("quilt-loader") {
+		(Loader::Quilt, Some(v.clone()))
+	} else {
+		(Loader::Vanilla, None)
+	};
+
+	let path = cluster::create::create_cluster(
+		index.name.clone(),
+		mc_version,
+		loader,
+		loader_version,
+		None,
+		None,
+		None,
+		None,
+		None,
+	)
+	.await?;
+
+	cluster::get(&path, None)
+		.await?
+		.ok_or_else(|| anyhow::anyhow!("cluster does not exist"))
+}
+
+/// Copy every `overrides/` and `client-overrides/` (note the hyphen) entry
+/// into `root`, stripping the prefix. Directory entries (names ending in `/`)
+/// are skipped — their parents are created on demand for each file.
+fn copy_overrides<R: Read + std::io::Seek>(
+	archive: &mut ZipArchive<R>,
+	root: &Path,
+) -> Result<()> {
+	for i in 0..archive.len() {
+		let mut entry = archive.by_index(i)?;
+		let name = entry.name().to_string();
+
+		if name.ends_with('/') {
+			continue;
+		}
+
+		let relative = if let Some(rest) = name.strip_prefix("overrides/") {
+			rest
+		} else if let Some(rest) = name.strip_prefix("client-overrides/") {
+			rest
+		} else {
+			continue;
+		};
+
+		let dest = root.join(relative);
+		if let Some(parent) = dest.parent() {
+			std::fs::create_dir_all(parent)?;
+		}
+		let mut out = std::fs::File::create(&dest)?;
+		std::io::copy(&mut entry, &mut out)?;
+	}
+
+	Ok(())
+}
+
+/// Serialize a cluster's tracked Modrinth files plus the selected override
+/// folders into a new `.mrpack` at `output`.
+pub async fn export_mrpack(
+	cluster: &Cluster,
+	output: PathBuf,
+	included_overrides: Vec<String>,
+) -> Result<()> {
+	let root = cluster.get_full_path().await?;
+
+	let mut files = Vec::new();
+	for pkg in cluster.modrinth_packages() {
+		files.push(IndexFile {
+			path: pkg.path.clone(),
+			hashes: Hashes {
+				sha1: pkg.sha1.clone(),
+			},
+			env: None,
+			downloads: vec![pkg.url.clone()],
+		});
+	}
+
+	let mut dependencies = std::collections::HashMap::new();
+	dependencies.insert("minecraft".to_string(), cluster.meta.mc_version.clone());
+	if let Some(v) = &cluster.meta.loader_version {
+		dependencies.insert(cluster.meta.loader.mrpack_key().to_string(), v.id.clone());
+	}
+
+	let index = ModrinthIndex {
+		format_version: 1,
+		name: cluster.meta.name.clone(),
+		version_id: cluster.meta.mc_version.clone(),
+		dependencies,
+		files,
+	};
+
+	let buf = std::fs::File::create(&output)?;
+	let mut zip = zip::ZipWriter::new(buf);
+	let options: zip::write::FileOptions<()> =
+		zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+	zip.start_file("modrinth.index.json", options)?;
+	zip.write_all(serde_json::to_string_pretty(&index)?.as_bytes())?;
+
+	for folder in included_overrides {
+		add_override_dir(&mut zip, &root, &folder, options)?;
+	}
+
+	zip.finish()?;
+	Ok(())
+}
+
+/// Recursively add `root/folder` to the archive under `overrides/folder`.
+fn add_override_dir<W: Write + std::io::Seek>(
+	zip: &mut zip::ZipWriter<W>,
+	root: &Path,
+	folder: &str,
+	options: zip::write::FileOptions<()>,
+) -> Result<()> {
+	let base = root.join(folder);
+	if !base.exists() {
+		return Ok(());
+	}
+
+	for entry in walkdir::WalkDir::new(&base).into_iter().filter_map(std::result::Result::ok) {
+		let path = entry.path();
+		if !path.is_file() {
+			continue;
+		}
+		let relative = path.strip_prefix(root).unwrap_or(path);
+		let name = format!("overrides/{}", relative.to_string_lossy().replace('\\', "/"));
+		zip.start_file(name, options)?;
+		zip.write_all(&std::fs::read(path)?)?;
+	}
+
+	Ok(())
+}
@@ -0,0 +1,84 @@
+//! Cluster storage model.
+//!
+//! A `Cluster` is a single Minecraft instance: its metadata, the packages it
+//! tracks, and the per-instance overrides (Java runtime, JVM memory, groups).
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+use crate::data::Loader;
+use crate::store::memory::JvmMemory;
+use crate::Result;
+
+/// A resolved loader version (`id` is the loader's own version string).
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct LoaderVersion {
+	pub id: String,
+}
+
+/// A Modrinth file tracked on a cluster, used to round-trip `.mrpack` exports.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct TrackedPackage {
+	pub path: String,
+	pub sha1: String,
+	pub url: String,
+}
+
+/// Persisted metadata for a [`Cluster`].
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ClusterMeta {
+	pub name: String,
+	pub mc_version: String,
+	pub loader: Loader,
+	pub loader_version: Option<LoaderVersion>,
+	/// Organizational groups this instance belongs to. Older on-disk clusters
+	/// predate this field, so it defaults to empty rather than failing to load.
+	#[serde(default)]
+	pub groups: Vec<String>,
+	/// Path to the Java runtime this cluster launches with, if pinned.
+	#[serde(default)]
+	pub java_path: Option<PathBuf>,
+	/// Per-cluster JVM heap override; `None` falls back to the global default.
+	#[serde(default)]
+	pub memory: Option<JvmMemory>,
+}
+
+/// A single Minecraft instance.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct Cluster {
+	pub uuid: Uuid,
+	pub meta: ClusterMeta,
+	#[serde(default)]
+	pub packages: Vec<TrackedPackage>,
+}
+
+impl Cluster {
+	/// The tracked Modrinth files, used when exporting a `.mrpack`.
+	pub fn modrinth_packages(&self) -> impl Iterator<Item = &TrackedPackage> {
+		self.packages.iter()
+	}
+
+	/// Absolute path to this cluster's working directory on disk.
+	pub async fn get_full_path(&self) -> Result<PathBuf> {
+		crate::store::ClusterPath::find_by_uuid(self.uuid)
+			.await?
+			.full_path()
+			.await
+	}
+}
+
+impl Loader {
+	/// The `modrinth.index.json` `dependencies` key for this loader.
+	pub fn mrpack_key(&self) -> &'static str {
+		match self {
+			Loader::Vanilla => "minecraft",
+			Loader::Forge => "forge",
+			Loader::NeoForge => "neoforge",
+			Loader::Fabric => "fabric-loader",
+			Loader::Quilt => "quilt-loader",
+		}
+	}
+}
@@ -0,0 +1,31 @@
+//! Microsoft/Minecraft account credentials.
+//!
+//! Credentials are cached with their access-token expiry so the launch path
+//! can tell a stale token from a live one and refresh silently, without
+//! dragging the user back through the interactive OAuth flow.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+/// A logged-in Minecraft account.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct MinecraftCredentials {
+	/// The player's Minecraft profile uuid.
+	pub id: Uuid,
+	pub username: String,
+	pub access_token: String,
+	/// The Microsoft refresh token used to mint a new access token.
+	pub refresh_token: String,
+	/// When `access_token` stops being accepted by Minecraft services.
+	pub expires: DateTime<Utc>,
+}
+
+impl MinecraftCredentials {
+	/// Whether the access token is expired or within `window` of expiring and
+	/// should be refreshed before use.
+	pub fn is_expiring(&self, window: chrono::Duration) -> bool {
+		self.expires - Utc::now() <= window
+	}
+}
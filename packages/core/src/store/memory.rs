@@ -0,0 +1,35 @@
+//! JVM memory settings persisted in `Settings` (as the default) and overridable
+//! per [`Cluster`](super::Cluster).
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Minimum and maximum JVM heap sizes, in mebibytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct JvmMemory {
+	pub minimum: u32,
+	pub maximum: u32,
+}
+
+impl JvmMemory {
+	/// The default heap: a small floor plus a maximum derived as 40% of total
+	/// system RAM, clamped to a sane range so we neither starve the game nor
+	/// hand it the whole machine.
+	pub fn from_system_ram(total_bytes: u64) -> Self {
+		let total_mb = (total_bytes / 1024 / 1024) as u32;
+		let maximum = ((total_mb as f32 * 0.4) as u32).clamp(2048, 8192);
+		Self {
+			minimum: 512,
+			maximum,
+		}
+	}
+}
+
+impl Default for JvmMemory {
+	fn default() -> Self {
+		Self {
+			minimum: 512,
+			maximum: 4096,
+		}
+	}
+}
@@ -0,0 +1,9 @@
+//! On-disk state: clusters, accounts, and their persisted settings.
+
+pub mod cluster;
+pub mod credentials;
+pub mod memory;
+
+pub use cluster::{Cluster, ClusterMeta, LoaderVersion, TrackedPackage};
+pub use credentials::MinecraftCredentials;
+pub use memory::JvmMemory;
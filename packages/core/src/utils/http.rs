@@ -0,0 +1,16 @@
+//! Small HTTP helpers shared by the content providers and pack installers.
+
+use crate::Result;
+
+/// Download `url` fully into memory, returning its bytes. Errors on any
+/// non-success status so callers can fail fast before writing to disk.
+pub async fn fetch(url: &str) -> Result<Vec<u8>> {
+	let response = reqwest::get(url).await?.error_for_status()?;
+	Ok(response.bytes().await?.to_vec())
+}
+
+/// `GET` `url` and deserialize the JSON body into `T`.
+pub async fn get_json<T: serde::de::DeserializeOwned>(url: &str) -> Result<T> {
+	let response = reqwest::get(url).await?.error_for_status()?;
+	Ok(response.json::<T>().await?)
+}
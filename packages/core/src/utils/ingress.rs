@@ -0,0 +1,30 @@
+//! A minimal progress feed used by long-running operations.
+//!
+//! Core code never talks to the webview directly; instead it reports
+//! progress through a [`ProgressSink`] handed in by the caller. The desktop
+//! app implements the sink by forwarding each update to Tauri's event
+//! channel, while headless callers (tests, CLI) can pass [`NoopSink`].
+
+/// A sink that receives incremental progress for a single operation.
+///
+/// `fraction` is `Some(0.0..=1.0)` for measurable steps and `None` for steps
+/// whose completion cannot be quantified. Implementations must be cheap and
+/// must never panic — a closed channel is not an error for the work itself.
+pub trait ProgressSink: Send + Sync {
+	/// Report an incremental update for the operation.
+	fn update(&self, fraction: Option<f32>, message: &str);
+}
+
+/// A [`ProgressSink`] that discards every update. Useful for headless callers.
+pub struct NoopSink;
+
+impl ProgressSink for NoopSink {
+	fn update(&self, _fraction: Option<f32>, _message: &str) {}
+}
+
+/// Blanket impl so a plain closure can be used anywhere a sink is expected.
+impl<F: Fn(Option<f32>, &str) + Send + Sync> ProgressSink for F {
+	fn update(&self, fraction: Option<f32>, message: &str) {
+		self(fraction, message)
+	}
+}
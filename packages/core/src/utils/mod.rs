@@ -0,0 +1,26 @@
+//! Cross-cutting helpers used throughout the core crate.
+
+pub mod http;
+pub mod ingress;
+
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+/// Hex-encode the SHA1 digest of `data`. Used to verify downloaded pack files
+/// against the hashes declared in their manifests.
+pub fn sha1_hex(data: &[u8]) -> String {
+	hex_digest(Sha1::digest(data).as_slice())
+}
+
+/// Hex-encode the SHA-256 digest of `data`, matching the algorithm Adoptium
+/// publishes for its runtime archives.
+pub fn sha256_hex(data: &[u8]) -> String {
+	hex_digest(Sha256::digest(data).as_slice())
+}
+
+fn hex_digest(bytes: &[u8]) -> String {
+	bytes.iter().fold(String::with_capacity(bytes.len() * 2), |mut acc, b| {
+		acc.push_str(&format!("{b:02x}"));
+		acc
+	})
+}